@@ -0,0 +1,146 @@
+use clap::{Parser, ValueEnum};
+
+/// page: opens file(s) or stdin in a neovim terminal buffer
+#[derive(Debug, Parser)]
+#[clap(version, about)]
+pub struct Options {
+    /// Neovim session address, or $NVIM; comma-separated to try several in order
+    #[clap(short = 'a', long, env = "NVIM")]
+    pub address: Option<String>,
+
+    /// Config (lua or vimscript) to source before files are opened
+    #[clap(short = 'c', long)]
+    pub config: Option<String>,
+
+    /// Recursion depth: with no `files`, walk the current directory; with
+    /// `files`, descend into the directories they match. An optional depth
+    /// may follow (defaults to 1 when bare)
+    #[clap(long)]
+    pub recurse_depth: Option<Option<usize>>,
+
+    /// Open files even when they aren't detected as text
+    #[clap(long)]
+    pub open_non_text: bool,
+
+    /// Timestamp used to pick the file(s) to open when none are given on the
+    /// command line
+    #[clap(long, value_enum, default_value_t = SortBy::Mtime)]
+    pub sort_by: SortBy,
+
+    /// Pick the oldest file(s) by `sort_by` instead of the newest
+    #[clap(long)]
+    pub oldest: bool,
+
+    /// Open the top N files by `sort_by` instead of just one
+    #[clap(long, default_value_t = 1)]
+    pub recent: usize,
+
+    /// Move cursor to the end of the opened buffer
+    #[clap(short = 'f', long)]
+    pub follow: bool,
+
+    /// Move cursor to the first match of this pattern after opening
+    #[clap(short = 'p', long)]
+    pub pattern: Option<String>,
+
+    /// Move cursor to the first match of this pattern, searching backwards
+    #[clap(long)]
+    pub pattern_backwards: Option<String>,
+
+    /// Keep the buffer around until it's closed, and notify page when it is
+    #[clap(short = 'k', long)]
+    pub keep: bool,
+
+    /// Like `keep`, but the buffer is force-closed on the first write instead
+    #[clap(short = 'K', long)]
+    pub keep_until_write: bool,
+
+    /// Lua to execute in the opened buffer
+    #[clap(long)]
+    pub lua: Option<String>,
+
+    /// Ex command to execute in the opened buffer
+    #[clap(short = 'e', long)]
+    pub command: Option<String>,
+
+    /// Switch back to the window/buffer page was invoked from
+    #[clap(short = 'b', long)]
+    pub back: bool,
+
+    /// Like `back`, but also restores insert mode
+    #[clap(short = 'B', long)]
+    pub back_restore: bool,
+
+    #[clap(flatten)]
+    pub split: SplitOptions,
+
+    /// Files to open; if empty (and `recurse_depth` isn't given), the file(s)
+    /// in the current directory picked by `sort_by`, `oldest` and `recent`
+    /// are opened instead
+    pub files: Vec<String>,
+}
+
+impl Options {
+    pub fn is_split_implied(&self) -> bool {
+        self.split.is_implied()
+    }
+
+    /// Resolves the possibly-bare `--recurse-depth` into the depth actually used
+    pub fn effective_recurse_depth(&self) -> usize {
+        match self.recurse_depth {
+            Some(Some(n)) => n,
+            Some(None) => 1,
+            None => 0,
+        }
+    }
+}
+
+#[derive(Debug, Default, Parser)]
+pub struct SplitOptions {
+    /// Open a popup window instead of a split
+    #[clap(short = 'P', long)]
+    pub popup: bool,
+
+    #[clap(short = 'r', long, default_value_t = 0)]
+    pub split_right: usize,
+    #[clap(short = 'l', long, default_value_t = 0)]
+    pub split_left: usize,
+    #[clap(short = 'd', long, default_value_t = 0)]
+    pub split_below: usize,
+    #[clap(short = 'u', long, default_value_t = 0)]
+    pub split_above: usize,
+
+    #[clap(short = 'R', long)]
+    pub split_right_cols: Option<usize>,
+    #[clap(short = 'L', long)]
+    pub split_left_cols: Option<usize>,
+    #[clap(short = 'D', long)]
+    pub split_below_rows: Option<usize>,
+    #[clap(short = 'U', long)]
+    pub split_above_rows: Option<usize>,
+}
+
+impl SplitOptions {
+    pub fn is_implied(&self) -> bool {
+        self.split_right != 0
+            || self.split_left != 0
+            || self.split_below != 0
+            || self.split_above != 0
+            || self.split_right_cols.is_some()
+            || self.split_left_cols.is_some()
+            || self.split_below_rows.is_some()
+            || self.split_above_rows.is_some()
+    }
+}
+
+pub fn get_options() -> Options {
+    Options::parse()
+}
+
+/// Which file timestamp to sort candidates by, picked via `--sort-by`
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum SortBy {
+    Mtime,
+    Atime,
+    Ctime,
+}
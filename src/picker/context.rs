@@ -29,14 +29,17 @@ pub mod env_context {
 
         let mut files_usage = FilesUsage::FilesProvided;
         if opt.files.is_empty() {
-            files_usage = FilesUsage::LastModifiedFile;
+            files_usage = FilesUsage::LastModifiedFile {
+                sort_by: opt.sort_by,
+                oldest: opt.oldest,
+                count: opt.recent.max(1),
+            };
         }
-        let recurse_depth = match opt.recurse_depth {
-            Some(Some(n)) => n,
-            Some(None) => 1,
-            None => 0,
-        };
-        if recurse_depth > 0 {
+        // With files/patterns given, FilesProvided owns recursion itself (see
+        // `open_files::expand_patterns`); only `--recurse-depth` with no
+        // files walks the current directory directly.
+        let recurse_depth = opt.effective_recurse_depth();
+        if recurse_depth > 0 && opt.files.is_empty() {
             files_usage = FilesUsage::RecursiveCurrentDir { recurse_depth }
         }
 
@@ -85,7 +88,11 @@ pub mod env_context {
         RecursiveCurrentDir {
             recurse_depth: usize,
         },
-        LastModifiedFile,
+        LastModifiedFile {
+            sort_by: crate::cli::SortBy,
+            oldest: bool,
+            count: usize,
+        },
         FilesProvided,
     }
 
@@ -49,19 +49,97 @@ async fn connect_neovim(env_ctx: context::EnvContext) {
 
     connection::init_panic_hook();
 
-    let nvim_conn = connection::open(
-        &env_ctx.tmp_dir,
-        &env_ctx.page_id,
-        &env_ctx.opt.address,
-        &env_ctx.opt.config,
-        &env_ctx.opt.config,
-        false
-    ).await;
+    use connection_state::ConnectionState;
+
+    const MAX_ATTEMPTS: u32 = 3;
+    const RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(250);
+
+    let addresses: Vec<String> = env_ctx.opt.address
+        .as_deref()
+        .map(|addrs| addrs.split(',').map(str::trim).filter(|a| !a.is_empty()).map(String::from).collect())
+        .unwrap_or_default();
+
+    let mut state = ConnectionState::Uninitialized;
+
+    'addresses: for address in &addresses {
+        state = ConnectionState::InitInProgress;
+        log::debug!(target: "connection", "state: {state:?}, connecting to `{address}`");
+
+        for attempt in 1..=MAX_ATTEMPTS {
+            let (tmp_dir, page_id, config, addr) = (
+                env_ctx.tmp_dir.clone(), env_ctx.page_id.clone(),
+                env_ctx.opt.config.clone(), Some(address.clone())
+            );
+
+            // `connection::open` panics rather than returning a `Result`, so
+            // each attempt runs on its own task: a panic there surfaces as a
+            // `JoinError` here instead of aborting the whole process, which is
+            // what lets a bad candidate address be retried, and then what lets
+            // the next address in the list be tried at all.
+            let attempt_result = tokio::spawn(async move {
+                connection::open(&tmp_dir, &page_id, &addr, &config, &config, false).await
+            }).await;
+
+            match attempt_result {
+                Ok(conn) => {
+                    state = ConnectionState::Initialized(conn);
+                    break 'addresses
+                },
+                Err(join_err) => {
+                    log::warn!(
+                        target: "connection",
+                        "Attempt {attempt}/{MAX_ATTEMPTS} to connect to `{address}` failed: {join_err}"
+                    );
+                    if attempt < MAX_ATTEMPTS {
+                        tokio::time::sleep(RETRY_DELAY).await;
+                    }
+                }
+            }
+        }
+
+        log::warn!(target: "connection", "Giving up on `{address}` after {MAX_ATTEMPTS} attempts");
+        state = ConnectionState::Error(address.clone());
+    }
+
+    let nvim_conn = match state {
+        ConnectionState::Initialized(conn) => conn,
+        _ => {
+            if !addresses.is_empty() {
+                log::warn!(
+                    target: "connection",
+                    "All {} candidate address(es) were unreachable, spawning a Neovim instance instead",
+                    addresses.len()
+                );
+            }
+            connection::open(
+                &env_ctx.tmp_dir,
+                &env_ctx.page_id,
+                &None,
+                &env_ctx.opt.config,
+                &env_ctx.opt.config,
+                false
+            ).await
+        }
+    };
 
     open_files(env_ctx, nvim_conn).await
 }
 
 
+mod connection_state {
+    /// Lifecycle of the connection to a single candidate Neovim address, driving
+    /// the retry/failover loop in `connect_neovim` instead of that loop relying
+    /// only on raw booleans.
+    #[derive(Debug)]
+    pub enum ConnectionState<C> {
+        Uninitialized,
+        InitInProgress,
+        Initialized(C),
+        Error(String),
+    }
+}
+
+
 async fn open_files(env_ctx: context::EnvContext, mut conn: NeovimConnection) {
 
     if env_ctx.opt.is_split_implied() {
@@ -89,8 +167,8 @@ async fn open_files(env_ctx: context::EnvContext, mut conn: NeovimConnection) {
                 open_files::open_file(&mut conn, &env_ctx, &f.path_string).await;
             }
         },
-        FilesUsage::LastModifiedFile => {
-            let mut last_modified = None;
+        FilesUsage::LastModifiedFile { sort_by, oldest, count } => {
+            let mut candidates = Vec::new();
 
             let read_dir = std::fs::read_dir("./").expect("Cannot read current directory");
             for f in read_dir {
@@ -101,25 +179,24 @@ async fn open_files(env_ctx: context::EnvContext, mut conn: NeovimConnection) {
                     continue;
                 }
 
-                let f_modified_time = f.get_modified_time();
+                let f_time = f.get_timestamp(sort_by);
+                candidates.push((f_time, f));
+            }
 
-                if let Some((last_modified_time, last_modified)) = last_modified.as_mut() {
-                    if *last_modified_time < f_modified_time {
-                        (*last_modified_time, *last_modified) = (f_modified_time, f);
-                    }
-                } else {
-                    last_modified.replace((f_modified_time, f));
-                }
+            candidates.sort_by_key(|(t, _)| *t);
+            if !oldest {
+                candidates.reverse();
             }
 
-            if let Some((_, f)) = last_modified {
+            for (_, f) in candidates.into_iter().take(count) {
                 open_files::open_file(&mut conn, &env_ctx, &f.path_string).await;
             }
         },
         FilesUsage::FilesProvided => {
-            for f in &env_ctx.opt.files {
-                let f = open_files::FileToOpen::new(f.as_str());
+            let recurse_depth = env_ctx.opt.effective_recurse_depth();
+            let files = open_files::expand_patterns(&env_ctx.opt.files, recurse_depth);
 
+            for f in files {
                 if !f.is_text && !env_ctx.opt.open_non_text {
                     continue
                 }
@@ -145,7 +222,9 @@ async fn open_files(env_ctx: context::EnvContext, mut conn: NeovimConnection) {
 
 
 mod open_files {
-    use std::{path::{PathBuf, Path}, time::SystemTime};
+    use std::path::{PathBuf, Path};
+    #[cfg(not(unix))]
+    use std::time::SystemTime;
     use crate::context::EnvContext;
 
     use once_cell::unsync::Lazy;
@@ -173,30 +252,190 @@ mod open_files {
             }
         }
 
-        pub fn get_modified_time(&self) -> SystemTime {
+        /// Timestamp used to order candidates, as a `(whole_seconds, nanos)` key so
+        /// that ties within the same second still compare correctly.
+        #[cfg(unix)]
+        pub fn get_timestamp(&self, sort_by: crate::cli::SortBy) -> (i64, i64) {
+            use std::os::unix::fs::MetadataExt;
+            use crate::cli::SortBy;
+
             let f_meta = self.path
                 .metadata()
                 .expect("Cannot read dir entry metadata");
-            f_meta
-                .modified()
-                .expect("Cannot read modified metadata")
+
+            match sort_by {
+                SortBy::Mtime => (f_meta.mtime(), f_meta.mtime_nsec()),
+                SortBy::Atime => (f_meta.atime(), f_meta.atime_nsec()),
+                SortBy::Ctime => (f_meta.ctime(), f_meta.ctime_nsec()),
+            }
         }
+
+        #[cfg(not(unix))]
+        pub fn get_timestamp(&self, sort_by: crate::cli::SortBy) -> (i64, i64) {
+            use crate::cli::SortBy;
+
+            let f_meta = self.path
+                .metadata()
+                .expect("Cannot read dir entry metadata");
+
+            let t = match sort_by {
+                SortBy::Mtime => f_meta.modified(),
+                SortBy::Atime => f_meta.accessed(),
+                SortBy::Ctime => f_meta.created(),
+            }.unwrap_or_else(|_| panic!("Timestamp {sort_by:?} is unavailable on this platform"));
+
+            let secs = t.duration_since(SystemTime::UNIX_EPOCH)
+                .expect("File timestamp is before the Unix epoch");
+            (secs.as_secs() as i64, secs.subsec_nanos() as i64)
+        }
+    }
+
+    /// Expands shell-independent glob and brace patterns in `patterns` into the
+    /// files to actually open, recursing into matched directories up to
+    /// `recurse_depth` (same meaning as `--recurse-depth`). A pattern matching
+    /// nothing is opened literally, with a warning, rather than dropped.
+    pub fn expand_patterns(patterns: &[String], recurse_depth: usize) -> Vec<FileToOpen> {
+        let mut files = Vec::new();
+
+        // Each pattern's own glob/recursion matches are sorted for determinism
+        // (glob order isn't guaranteed), but patterns are kept in argument
+        // order so e.g. `page b.txt a.txt` still opens `b.txt` first.
+        for pattern in patterns {
+            let mut pattern_matches = Vec::new();
+
+            for expanded in expand_braces(pattern) {
+                let paths = match glob::glob(&expanded) {
+                    Ok(paths) => paths,
+                    Err(e) => {
+                        log::warn!(target: "usage", "Invalid pattern `{expanded}`: {e}, skipping");
+                        continue
+                    }
+                };
+
+                for path in paths.flatten() {
+                    if path.is_dir() {
+                        if recurse_depth > 0 {
+                            let read_dir = walkdir::WalkDir::new(&path)
+                                .contents_first(true)
+                                .follow_links(false)
+                                .max_depth(recurse_depth);
+
+                            for f in read_dir.into_iter().flatten() {
+                                if f.file_type().is_file() {
+                                    pattern_matches.push(f.into_path());
+                                }
+                            }
+                        }
+                        continue
+                    }
+
+                    pattern_matches.push(path);
+                }
+            }
+
+            if pattern_matches.is_empty() {
+                log::warn!(target: "usage", "Pattern `{pattern}` matched no files, opening literally");
+                files.push(FileToOpen::new(pattern.as_str()));
+                continue
+            }
+
+            pattern_matches.sort();
+            files.extend(pattern_matches.into_iter().map(FileToOpen::new));
+        }
+
+        files
+    }
+
+    /// Recursively expands `{a,b,c}` brace groups, e.g. `{a,b}.log` -> `a.log`, `b.log`.
+    /// Non-brace patterns are returned unchanged.
+    fn expand_braces(pattern: &str) -> Vec<String> {
+        let chars: Vec<char> = pattern.chars().collect();
+
+        // Find the outermost top-level `{...}` group, ignoring braces and commas
+        // that fall inside a `[...]` glob character class.
+        let (mut start, mut end, mut depth, mut in_class) = (None, None, 0i32, false);
+        for (i, &c) in chars.iter().enumerate() {
+            match c {
+                '[' if !in_class => in_class = true,
+                ']' if in_class => in_class = false,
+                '{' if !in_class => {
+                    if depth == 0 { start.get_or_insert(i); }
+                    depth += 1;
+                },
+                '}' if !in_class && depth > 0 => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = Some(i);
+                        break
+                    }
+                },
+                _ => {}
+            }
+        }
+
+        let (Some(start), Some(end)) = (start, end) else {
+            return vec![pattern.to_string()]
+        };
+
+        let prefix: String = chars[..start].iter().collect();
+        let suffix: String = chars[end + 1..].iter().collect();
+
+        // Split the group's contents on top-level commas only, so nested brace
+        // groups and commas inside `[...]` character classes stay intact.
+        let (mut alts, mut alt, mut depth, mut in_class) = (Vec::new(), String::new(), 0i32, false);
+        for &c in &chars[start + 1..end] {
+            match c {
+                '[' if !in_class => { in_class = true; alt.push(c); },
+                ']' if in_class => { in_class = false; alt.push(c); },
+                '{' if !in_class => { depth += 1; alt.push(c); },
+                '}' if !in_class => { depth -= 1; alt.push(c); },
+                ',' if !in_class && depth == 0 => alts.push(std::mem::take(&mut alt)),
+                _ => alt.push(c),
+            }
+        }
+        alts.push(alt);
+
+        alts.into_iter()
+            .flat_map(|alt| expand_braces(&format!("{prefix}{alt}{suffix}")))
+            .collect()
     }
 
+    // Bytes read from the start of a candidate file to classify it. Large enough
+    // to catch binary markers that show up after a text-looking header.
+    const SNIFF_LEN: usize = 8 * 1024;
+
     pub fn is_text_file(f: &str) -> bool {
-        let file_cmd = std::process::Command::new("file")
-            .arg(f)
-            .output()
-            .expect("Cannot get `file` output");
-        let file_cmd_output = String::from_utf8(file_cmd.stdout)
-            .expect("Non UTF8 `file` output");
-
-        let filetype = file_cmd_output
-            .split(": ")
-            .last()
-            .expect("Wrong `file` output format");
-
-        filetype == "ASCII text\n"
+        use std::io::Read;
+
+        let Ok(mut file) = std::fs::File::open(f) else {
+            return false
+        };
+        let mut bytes = Vec::new();
+        if file.by_ref().take(SNIFF_LEN as u64).read_to_end(&mut bytes).is_err() {
+            return false
+        }
+        let sniff = &bytes;
+
+        if sniff.contains(&0) {
+            return false
+        }
+
+        let sniff = sniff.strip_prefix(b"\xef\xbb\xbf").unwrap_or(sniff);
+
+        if std::str::from_utf8(sniff).is_ok() {
+            return true
+        }
+
+        // Not valid UTF-8: fall back to a printable/control byte ratio heuristic
+        // to still accept legacy (e.g. Latin-1) encodings `file` would call text.
+        if sniff.is_empty() {
+            return true
+        }
+        let control_bytes = sniff.iter()
+            .filter(|&&b| b < 0x20 && b != b'\t' && b != b'\n' && b != b'\r')
+            .count();
+
+        (control_bytes as f32 / sniff.len() as f32) < 0.3
     }
 
 